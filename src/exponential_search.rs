@@ -0,0 +1,126 @@
+//! exponential (galloping) search, for when the match is expected near the start of a slice.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+
+use super::find_bound::find_min_match;
+
+pub trait ExponentialSearch<T> {
+    fn exponential_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering;
+    fn partition_point_exponential<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool;
+}
+
+impl<T> ExponentialSearch<T> for [T] {
+    /// Search the slice with a comparator, starting from a bound of 1 that doubles until it
+    /// overshoots the match, then binary searches only within that bound.
+    ///
+    /// This is `O(log i)` where `i` is the index of the match, which beats a full-range binary
+    /// search when the match is expected near the start of the slice, while degrading
+    /// gracefully to `O(log n)` otherwise.
+    /// # Arguments
+    /// * `f` - The comparator. Must return `Less` for elements before the match, `Equal` for
+    ///   the match, and `Greater` for elements after, as for `[T]::binary_search_by`.
+    /// # Errors
+    /// Returns `Err` with the index where a matching element could be inserted to keep the slice sorted.
+    /// # Examples
+    /// ```
+    /// use bsearch::ExponentialSearch;
+    /// let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+    /// assert_eq!(arr.exponential_search_by(|x| x.cmp(&4)), Ok(2));
+    /// assert_eq!(arr.exponential_search_by(|x| x.cmp(&2)), Err(1));
+    /// assert_eq!(arr.exponential_search_by(|x| x.cmp(&10)), Err(8));
+    /// ```
+    fn exponential_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let len = self.len();
+        if len == 0 {
+            return Err(0);
+        }
+        let mut bound = 1;
+        while bound < len && f(&self[bound]) == Ordering::Less {
+            bound *= 2;
+        }
+        let lo = bound / 2;
+        let hi = if bound < len { bound + 1 } else { len };
+
+        let f = RefCell::new(f);
+        let idx = match find_min_match(lo..hi, |&i| f.borrow_mut()(&self[i]) != Ordering::Less) {
+            Ok(i) => i,
+            Err(_) => hi,
+        };
+        if idx < len && f.borrow_mut()(&self[idx]) == Ordering::Equal {
+            Ok(idx)
+        } else {
+            Err(idx)
+        }
+    }
+
+    /// Find the index of the first element for which `pred` returns `false`, starting from a
+    /// bound of 1 that doubles until it overshoots the partition point.
+    ///
+    /// `pred` must be `true` for a prefix of the slice and `false` for the remainder, as for
+    /// `[T]::partition_point`.
+    /// # Arguments
+    /// * `pred` - The partitioning predicate.
+    /// # Examples
+    /// ```
+    /// use bsearch::ExponentialSearch;
+    /// let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+    /// assert_eq!(arr.partition_point_exponential(|&x| x < 4), 2);
+    /// ```
+    fn partition_point_exponential<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        if len == 0 {
+            return 0;
+        }
+        let mut bound = 1;
+        while bound < len && pred(&self[bound]) {
+            bound *= 2;
+        }
+        let lo = bound / 2;
+        let hi = if bound < len { bound + 1 } else { len };
+
+        let pred = RefCell::new(pred);
+        match find_min_match(lo..hi, |&i| !pred.borrow_mut()(&self[i])) {
+            Ok(i) => i,
+            Err(_) => hi,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_exponential_search_by_ok() {
+        let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+        assert_eq!(arr.exponential_search_by(|x| x.cmp(&1)), Ok(0));
+        assert_eq!(arr.exponential_search_by(|x| x.cmp(&4)), Ok(2));
+        assert_eq!(arr.exponential_search_by(|x| x.cmp(&9)), Ok(7));
+    }
+
+    #[test]
+    fn test_exponential_search_by_err() {
+        let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+        assert_eq!(arr.exponential_search_by(|x| x.cmp(&2)), Err(1));
+        assert_eq!(arr.exponential_search_by(|x| x.cmp(&10)), Err(8));
+    }
+
+    #[test]
+    fn test_partition_point_exponential() {
+        let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+        assert_eq!(arr.partition_point_exponential(|&x| x < 4), 2);
+        assert_eq!(arr.partition_point_exponential(|&x| x < 10), 8);
+        assert_eq!(arr.partition_point_exponential(|&x| x < 0), 0);
+    }
+}
@@ -1,11 +1,59 @@
 //! core functions for binary search.
-use std::ops::{Add, Div, Range, Sub};
+use std::ops::{Add, Bound, Div, Range, RangeBounds, Sub};
+
+/// Types with a minimum and maximum representable value.
+///
+/// This is needed to resolve an `Unbounded` end of a [`RangeBounds`] passed to
+/// [`find_min_match`]/[`find_max_match`] into a concrete value.
+pub trait Bounded {
+    const MIN: Self;
+    const MAX: Self;
+}
+
+macro_rules! impl_bounded {
+    ($($t:ty),*) => {
+        $(
+            impl Bounded for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+            }
+        )*
+    };
+}
+
+impl_bounded!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Resolve a [`RangeBounds`] into an inclusive `start..=end`, or `None` if it is empty.
+fn resolve_range<T, R>(range: R) -> Option<(T, T)>
+where
+    T: Ord + Add<Output = T> + Sub<Output = T> + Copy + From<u8> + Bounded,
+    R: RangeBounds<T>,
+{
+    let one = T::from(1);
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) if s == T::MAX => return None,
+        Bound::Excluded(&s) => s + one,
+        Bound::Unbounded => T::MIN,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e,
+        Bound::Excluded(&e) if e == T::MIN => return None,
+        Bound::Excluded(&e) => e - one,
+        Bound::Unbounded => T::MAX,
+    };
+    if start > end {
+        None
+    } else {
+        Some((start, end))
+    }
+}
 
 /// Find the minimum value that satisfies the condition of function `f`.
 /// # Arguments
-/// * `range` - The search range. start is inclusive, end is exclusive.
-/// * `f` - The condition function. f must be a monotonically increasing.
-/// [Monotonic_function](https://en.wikipedia.org/wiki/Monotonic_function)
+/// * `range` - The search range, e.g. `0..10`, `0..=9`, `..10` or `0..`.
+/// * `f` - The condition function. f must be a [monotonically increasing
+///   function](https://en.wikipedia.org/wiki/Monotonic_function).
 /// # Errors
 /// Returns `Err` with the last element of the range if no value in the range satisfies the condition.
 /// # Examples
@@ -17,18 +65,32 @@ use std::ops::{Add, Div, Range, Sub};
 ///
 /// let f = |&x: &i32| 3*x >= 10;
 /// assert_eq!(find_min_match(-100..100i32, f), Ok(4));
+/// assert_eq!(find_min_match(-100..=99i32, f), Ok(4));
+/// assert_eq!(find_min_match(0.., |&x: &i32| x >= 4), Ok(4));
 ///
 /// assert_eq!(find_min_match(-100..100i32, |_|false), Err(99));
 /// ```
-pub fn find_min_match<F, T>(range: Range<T>, f: F) -> Result<T, T>
+pub fn find_min_match<F, T, R>(range: R, f: F) -> Result<T, T>
 where
-    T: Ord + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Copy + From<u8>,
+    T: Ord + Add<Output = T> + Sub<Output = T> + Div<Output = T> + Copy + From<u8> + Bounded,
+    R: RangeBounds<T>,
     F: Fn(&T) -> bool,
 {
-    let (one, two) = (1.into(), 2.into());
-    let (mut left, mut right) = (range.start, range.end - one);
+    let (zero, one, two) = (T::from(0), T::from(1), T::from(2));
+    let Some((mut left, mut right)) = resolve_range(range) else {
+        return Err(T::MIN);
+    };
     while left < right {
-        let mid = (right + left) / two;
+        // `left + (right - left) / two` when both ends have the same sign, and the floored
+        // `(left + right) / two` otherwise, so this doesn't overflow when an unbounded range
+        // end resolves to `T::MIN` or `T::MAX`.
+        let mid = if (left < zero) == (right < zero) {
+            left + (right - left) / two
+        } else {
+            let sum = left + right;
+            let half = sum / two;
+            if sum < zero && half + half != sum { half - one } else { half }
+        };
         if f(&mid) {
             right = mid;
         } else {
@@ -45,9 +107,9 @@ where
 
 /// Find the maximum value that satisfies the condition of function `f`.
 /// # Arguments
-/// * `range` - The search range. start is inclusive, end is exclusive.
-/// * `f` - The condition function. f must be a monotonically decreasing.
-/// [Monotonic_function](https://en.wikipedia.org/wiki/Monotonic_function)
+/// * `range` - The search range, e.g. `0..10`, `0..=9`, `..10` or `0..`.
+/// * `f` - The condition function. f must be a [monotonically decreasing
+///   function](https://en.wikipedia.org/wiki/Monotonic_function).
 /// # Errors
 /// Returns `Err` with the first element of the range if no value in the range satisfies the condition.
 /// # Examples
@@ -58,18 +120,32 @@ where
 ///
 /// let f = |&x: &i32| 3*x <= 10;
 /// assert_eq!(find_max_match(-100..100i32, f), Ok(3));
+/// assert_eq!(find_max_match(-100..=99i32, f), Ok(3));
+/// assert_eq!(find_max_match(0.., |&x: &i32| x <= 3), Ok(3));
 ///
 /// assert_eq!(find_max_match(-100..100i32, |_|false), Err(-100));
 /// ```
-pub fn find_max_match<F, T>(range: Range<T>, f: F) -> Result<T, T>
+pub fn find_max_match<F, T, R>(range: R, f: F) -> Result<T, T>
 where
-    T: Ord + Add<Output = T> + Sub<Output = T> + Div<Output = T> + From<u8> + Copy,
+    T: Ord + Add<Output = T> + Sub<Output = T> + Div<Output = T> + From<u8> + Copy + Bounded,
+    R: RangeBounds<T>,
     F: Fn(&T) -> bool,
 {
-    let (one, two) = (1.into(), 2.into());
-    let (mut left, mut right) = (range.start, range.end - one);
+    let (zero, one, two) = (T::from(0), T::from(1), T::from(2));
+    let Some((mut left, mut right)) = resolve_range(range) else {
+        return Err(T::MAX);
+    };
     while left < right {
-        let mid = (right + left + one) / two;
+        // `right - (right - left) / two` when both ends have the same sign, and the ceiled
+        // `(left + right) / two` otherwise, so this doesn't overflow when an unbounded range
+        // end resolves to `T::MIN` or `T::MAX`.
+        let mid = if (left < zero) == (right < zero) {
+            right - (right - left) / two
+        } else {
+            let sum = left + right;
+            let half = sum / two;
+            if sum > zero && half + half != sum { half + one } else { half }
+        };
         if f(&mid) {
             left = mid;
         } else {
@@ -83,6 +159,74 @@ where
     }
 }
 
+/// The number of iterations used by [`find_min_match_f64`] and [`find_max_match_f64`]
+/// to converge on a continuous domain.
+const F64_ITERATIONS: u32 = 100;
+
+/// Find the minimum value in a continuous range that satisfies the condition of function `f`.
+///
+/// Unlike [`find_min_match`], this operates over `f64` rather than an `Ord` integer type, so it
+/// runs a fixed number of iterations instead of narrowing `left..right` to a single point.
+/// # Arguments
+/// * `range` - The search range. start is inclusive, end is exclusive.
+/// * `f` - The condition function. f must be a [monotonically increasing
+///   function](https://en.wikipedia.org/wiki/Monotonic_function).
+/// # Examples
+/// ```
+/// use bsearch::find_min_match_f64;
+///
+/// let f = |x: f64| x * x >= 2.0;
+/// let root = find_min_match_f64(0.0..2.0, f);
+/// assert!((root - std::f64::consts::SQRT_2).abs() < 1e-9);
+/// ```
+pub fn find_min_match_f64<F>(range: Range<f64>, f: F) -> f64
+where
+    F: Fn(f64) -> bool,
+{
+    let (mut left, mut right) = (range.start, range.end);
+    for _ in 0..F64_ITERATIONS {
+        let mid = (left + right) / 2.0;
+        if f(mid) {
+            right = mid;
+        } else {
+            left = mid;
+        }
+    }
+    right
+}
+
+/// Find the maximum value in a continuous range that satisfies the condition of function `f`.
+///
+/// Unlike [`find_max_match`], this operates over `f64` rather than an `Ord` integer type, so it
+/// runs a fixed number of iterations instead of narrowing `left..right` to a single point.
+/// # Arguments
+/// * `range` - The search range. start is inclusive, end is exclusive.
+/// * `f` - The condition function. f must be a [monotonically decreasing
+///   function](https://en.wikipedia.org/wiki/Monotonic_function).
+/// # Examples
+/// ```
+/// use bsearch::find_max_match_f64;
+///
+/// let f = |x: f64| x * x <= 2.0;
+/// let root = find_max_match_f64(0.0..2.0, f);
+/// assert!((root - std::f64::consts::SQRT_2).abs() < 1e-9);
+/// ```
+pub fn find_max_match_f64<F>(range: Range<f64>, f: F) -> f64
+where
+    F: Fn(f64) -> bool,
+{
+    let (mut left, mut right) = (range.start, range.end);
+    for _ in 0..F64_ITERATIONS {
+        let mid = (left + right) / 2.0;
+        if f(mid) {
+            left = mid;
+        } else {
+            right = mid;
+        }
+    }
+    left
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -125,4 +269,40 @@ mod test {
         let f = |&x: &i32| x <= -101;
         assert_eq!(find_max_match(-100..100i32, f), Err(-100));
     }
+
+    #[test]
+    fn test_find_min_match_range_bounds() {
+        let f = |&x: &i32| 3 * x >= 10;
+        assert_eq!(find_min_match(-100..=99i32, f), Ok(4));
+        assert_eq!(find_min_match(..=-1i32, |&x: &i32| x >= -5), Ok(-5));
+        assert_eq!(find_min_match(0.., |&x: &i32| x >= 4), Ok(4));
+    }
+
+    #[test]
+    fn test_find_min_match_no_overflow_near_max() {
+        let f = |&x: &u32| x >= u32::MAX - 2;
+        assert_eq!(find_min_match(0..=u32::MAX, f), Ok(u32::MAX - 2));
+    }
+
+    #[test]
+    fn test_find_max_match_range_bounds() {
+        let f = |&x: &i32| 3 * x <= 10;
+        assert_eq!(find_max_match(-100..=99i32, f), Ok(3));
+        assert_eq!(find_max_match(0.., |&x: &i32| x <= 3), Ok(3));
+        assert_eq!(find_max_match(..=-1i32, |&x: &i32| x <= -5), Ok(-5));
+    }
+
+    #[test]
+    fn test_find_min_match_f64() {
+        let f = |x: f64| x * x >= 2.0;
+        let root = find_min_match_f64(0.0..2.0, f);
+        assert!((root - std::f64::consts::SQRT_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_find_max_match_f64() {
+        let f = |x: f64| x * x <= 2.0;
+        let root = find_max_match_f64(0.0..2.0, f);
+        assert!((root - std::f64::consts::SQRT_2).abs() < 1e-9);
+    }
 }
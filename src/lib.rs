@@ -1,8 +1,10 @@
 //! This crate provides binary search algorithms.
+pub mod exponential_search;
 pub mod find_bound;
 pub mod slice_range;
 pub mod slice_search;
 
+pub use exponential_search::ExponentialSearch;
 pub use find_bound::*;
 pub use slice_range::Range;
 pub use slice_search::SliceSearch;
@@ -26,35 +26,50 @@ where
     /// assert_eq!(arr.range(4..4), []);
     /// ```
     fn range(&self, range: R) -> &[T] {
-        let n = self.len();
-        let start = match range.start_bound() {
-            std::ops::Bound::Included(&s) => self.lower_bound(s).unwrap_or(0),
-            std::ops::Bound::Excluded(&s) => self.upper_bound(s).unwrap_or(0),
-            std::ops::Bound::Unbounded => 0,
-        };
-        let end = match range.end_bound() {
-            std::ops::Bound::Included(&e) => self.upper_bound(e).unwrap_or(n),
-            std::ops::Bound::Excluded(&e) => self.lower_bound(e).unwrap_or(n),
-            std::ops::Bound::Unbounded => self.len(),
-        };
+        let (start, end) = resolve_bounds(self, range);
         &self[start..end]
     }
     fn range_mut(&mut self, range: R) -> &mut [T] {
-        let n = self.len();
-        let start = match range.start_bound() {
-            std::ops::Bound::Included(&s) => self.lower_bound(s).unwrap_or(0),
-            std::ops::Bound::Excluded(&s) => self.upper_bound(s).unwrap_or(0),
-            std::ops::Bound::Unbounded => 0,
-        };
-        let end = match range.end_bound() {
-            std::ops::Bound::Included(&e) => self.upper_bound(e).unwrap_or(n),
-            std::ops::Bound::Excluded(&e) => self.lower_bound(e).unwrap_or(n),
-            std::ops::Bound::Unbounded => self.len(),
-        };
+        let (start, end) = resolve_bounds(self, range);
         &mut self[start..end]
     }
 }
 
+/// Resolve a [`RangeBounds`] into concrete `start..end` slice indices.
+///
+/// When both ends are an `Included` bound on the same value (e.g. `4..=4`), this delegates to
+/// `equal_range`, whose search for the end starts at the start instead of covering the whole
+/// slice again.
+fn resolve_bounds<T, R>(arr: &[T], range: R) -> (usize, usize)
+where
+    T: Ord + Copy,
+    R: RangeBounds<T>,
+{
+    let n = arr.len();
+    let same_value_bound = match (range.start_bound(), range.end_bound()) {
+        (std::ops::Bound::Included(&s), std::ops::Bound::Included(&e)) if s == e => Some(s),
+        _ => None,
+    };
+    if let Some(v) = same_value_bound {
+        let r = arr.equal_range(v);
+        return (r.start, r.end);
+    }
+    // `lower_bound`/`upper_bound` return `Err` with the last index they checked rather than `n`
+    // when nothing in the slice satisfies the bound, so the fallback here must be `n`, not `0`,
+    // for an out-of-range start bound too.
+    let start = match range.start_bound() {
+        std::ops::Bound::Included(&s) => arr.lower_bound(s).unwrap_or(n),
+        std::ops::Bound::Excluded(&s) => arr.upper_bound(s).unwrap_or(n),
+        std::ops::Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        std::ops::Bound::Included(&e) => arr.upper_bound(e).unwrap_or(n),
+        std::ops::Bound::Excluded(&e) => arr.lower_bound(e).unwrap_or(n),
+        std::ops::Bound::Unbounded => n,
+    };
+    (start, end)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -72,6 +87,14 @@ mod test {
         // count 3 <= x <= 5 in arr
         assert_eq!(arr.range(3..=5).len(), 6);
     }
+    #[test]
+    fn test_range_out_of_bounds() {
+        let arr = [1, 2, 3];
+        assert_eq!(arr.range(10..), []);
+        assert_eq!(arr.range(10..20), []);
+        assert_eq!(arr.range(..-10), []);
+    }
+
     #[test]
     fn test_range_mut() {
         let mut arr = [1, 3, 4, 4, 4, 5, 5, 7, 9];
@@ -1,10 +1,22 @@
 //! probides `lower_bound` and `upper_bound` for slices.
 
+use std::cmp::Ordering;
+
 use super::find_bound::find_min_match;
 
 pub trait SliceSearch<T> {
     fn lower_bound(&self, value: T) -> Result<usize, usize>;
     fn upper_bound(&self, value: T) -> Result<usize, usize>;
+    fn equal_range(&self, value: T) -> std::ops::Range<usize>
+    where
+        T: Copy;
+    fn binary_search_branchless<P>(&self, pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool;
+    fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering;
+    fn binary_search(&self, value: &T) -> Result<usize, usize>;
 }
 
 impl<T> SliceSearch<T> for [T]
@@ -48,6 +60,133 @@ where
     fn upper_bound(&self, value: T) -> Result<usize, usize> {
         find_min_match(0..self.len(), |&x| self[x] > value)
     }
+
+    /// Find the range of indices of elements equal to the given value.
+    ///
+    /// Shares work between the two bounds: the search for the upper bound starts at
+    /// `lower_bound`'s result instead of searching the whole slice again.
+    /// # Arguments
+    /// * `arr` - The array to search. arr must be sorted.
+    /// * `value` - The value to search for.
+    /// # Examples
+    /// ```
+    /// use bsearch::SliceSearch;
+    /// let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+    /// assert_eq!(arr.equal_range(4), 2..5);
+    /// assert_eq!(arr.equal_range(4).len(), 3);
+    ///
+    /// assert_eq!(arr.equal_range(2), 1..1);
+    /// ```
+    fn equal_range(&self, value: T) -> std::ops::Range<usize>
+    where
+        T: Copy,
+    {
+        let len = self.len();
+        let lo = match self.lower_bound(value) {
+            Ok(i) => i,
+            Err(_) => len,
+        };
+        let hi = match find_min_match(lo..len, |&x| self[x] > value) {
+            Ok(i) => i,
+            Err(_) => len,
+        };
+        lo..hi
+    }
+
+    /// Find the first element for which `pred` returns `true`, using a number of iterations
+    /// that depends only on `self.len()`, not on where the match is.
+    ///
+    /// Unlike `lower_bound`, which exits as soon as the comparison narrows to a single
+    /// element, this always runs `⌈log2(len)⌉` iterations and updates `base` with an
+    /// unconditional select instead of a data-dependent branch. That makes it slower on
+    /// average, but its branch pattern doesn't depend on the search outcome, which keeps the
+    /// CPU's branch predictor from being defeated on large, L2/L3-resident slices where
+    /// mispredicts dominate runtime.
+    /// # Arguments
+    /// * `pred` - Must be `false` for a prefix of the slice and `true` for the remainder, as
+    ///   for `lower_bound`. Note this is the opposite convention from `[T]::partition_point`,
+    ///   which wants `true` for the prefix and `false` for the remainder.
+    /// # Examples
+    /// ```
+    /// use bsearch::SliceSearch;
+    /// let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+    /// assert_eq!(arr.binary_search_branchless(|&x| x >= 4), 2);
+    /// assert_eq!(arr.binary_search_branchless(|&x| x >= 10), 8);
+    /// ```
+    fn binary_search_branchless<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        if self.is_empty() {
+            return 0;
+        }
+        let mut base = 0;
+        let mut size = self.len();
+        while size > 1 {
+            let half = size / 2;
+            let mid = base + half;
+            base = if pred(&self[mid]) { base } else { mid };
+            size -= half;
+        }
+        if pred(&self[base]) {
+            base
+        } else {
+            base + 1
+        }
+    }
+
+    /// Find an element matching a comparator, returning `Ok` with its index if found or `Err`
+    /// with the index where it could be inserted to keep the slice sorted.
+    ///
+    /// Unlike `lower_bound`/`upper_bound`, which deliberately keep narrowing past a match to
+    /// find a bound, this returns as soon as `f` reports `Equal`, so a lookup that lands on a
+    /// match exits in `O(1)` instead of always running the full `O(log n)` descent.
+    /// # Arguments
+    /// * `arr` - The array to search. arr must be sorted.
+    /// * `f` - The comparator. Must return `Less` for elements before the match, `Equal` for
+    ///   the match, and `Greater` for elements after.
+    /// # Errors
+    /// Returns `Err` with the index where a matching element could be inserted to keep the slice sorted.
+    /// # Examples
+    /// ```
+    /// use bsearch::SliceSearch;
+    /// let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+    /// assert_eq!(SliceSearch::binary_search_by(&arr[..], |x| x.cmp(&4)), Ok(4));
+    /// assert_eq!(SliceSearch::binary_search_by(&arr[..], |x| x.cmp(&2)), Err(1));
+    /// ```
+    fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let (mut left, mut right) = (0, self.len());
+        while left < right {
+            let mid = left + (right - left) / 2;
+            match f(&self[mid]) {
+                Ordering::Equal => return Ok(mid),
+                Ordering::Less => left = mid + 1,
+                Ordering::Greater => right = mid,
+            }
+        }
+        Err(left)
+    }
+
+    /// Find the given value, returning `Ok` with its index if found or `Err` with the index
+    /// where it could be inserted to keep the slice sorted.
+    /// # Arguments
+    /// * `arr` - The array to search. arr must be sorted.
+    /// * `value` - The value to search for.
+    /// # Errors
+    /// Returns `Err` with the index where `value` could be inserted to keep the slice sorted.
+    /// # Examples
+    /// ```
+    /// use bsearch::SliceSearch;
+    /// let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+    /// assert_eq!(SliceSearch::binary_search(&arr[..], &4), Ok(4));
+    /// assert_eq!(SliceSearch::binary_search(&arr[..], &2), Err(1));
+    /// ```
+    fn binary_search(&self, value: &T) -> Result<usize, usize> {
+        self.binary_search_by(|x| x.cmp(value))
+    }
 }
 
 #[cfg(test)]
@@ -81,4 +220,57 @@ mod test {
         let tf = [false, false, false, false, false];
         assert_eq!(tf.upper_bound(true), Err(4));
     }
+    #[test]
+    fn test_equal_range() {
+        let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+        assert_eq!(arr.equal_range(4), 2..5);
+        assert_eq!(arr.equal_range(1), 0..1);
+        assert_eq!(arr.equal_range(5), 5..6);
+        assert_eq!(arr.equal_range(9), 7..8);
+    }
+
+    #[test]
+    fn test_equal_range_matches_max_element() {
+        let arr = [1, 2, 2, 2, 3, 3];
+        assert_eq!(arr.equal_range(3), 4..6);
+    }
+    #[test]
+    fn test_equal_range_empty() {
+        let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+        assert_eq!(arr.equal_range(2), 1..1);
+        assert_eq!(arr.equal_range(6), 6..6);
+    }
+    #[test]
+    fn test_binary_search_branchless() {
+        let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+        assert_eq!(arr.binary_search_branchless(|&x| x >= 1), 0);
+        assert_eq!(arr.binary_search_branchless(|&x| x >= 4), 2);
+        assert_eq!(arr.binary_search_branchless(|&x| x >= 6), 6);
+        assert_eq!(arr.binary_search_branchless(|&x| x >= 10), 8);
+    }
+    #[test]
+    fn test_binary_search_branchless_empty() {
+        let arr: [i32; 0] = [];
+        assert_eq!(arr.binary_search_branchless(|&x| x >= 0), 0);
+    }
+    #[test]
+    fn test_binary_search_by_ok() {
+        let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+        assert_eq!(SliceSearch::binary_search_by(&arr[..], |x| x.cmp(&1)), Ok(0));
+        assert!(matches!(SliceSearch::binary_search_by(&arr[..], |x| x.cmp(&4)), Ok(2..=4)));
+        assert_eq!(SliceSearch::binary_search_by(&arr[..], |x| x.cmp(&9)), Ok(7));
+    }
+    #[test]
+    fn test_binary_search_by_err() {
+        let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+        assert_eq!(SliceSearch::binary_search_by(&arr[..], |x| x.cmp(&2)), Err(1));
+        assert_eq!(SliceSearch::binary_search_by(&arr[..], |x| x.cmp(&10)), Err(8));
+        assert_eq!(SliceSearch::binary_search_by(&arr[..], |x| x.cmp(&0)), Err(0));
+    }
+    #[test]
+    fn test_binary_search() {
+        let arr = [1, 3, 4, 4, 4, 5, 7, 9];
+        assert!(matches!(SliceSearch::binary_search(&arr[..], &4), Ok(2..=4)));
+        assert_eq!(SliceSearch::binary_search(&arr[..], &2), Err(1));
+    }
 }